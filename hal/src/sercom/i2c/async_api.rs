@@ -51,8 +51,26 @@ where
         I2cFuture {
             i2c: self,
             _dma_channel: NoneT,
+            _interrupt_guard: InterruptGuard {
+                _sercom: PhantomData,
+            },
+            #[cfg(feature = "embassy-time")]
+            timeout: None,
         }
     }
+
+    /// Turn an [`I2c`] configured for target (slave) mode into an
+    /// [`I2cTargetFuture`](target::I2cTargetFuture).
+    #[inline]
+    pub fn into_target_future<I>(self, _interrupts: I) -> target::I2cTargetFuture<C>
+    where
+        I: Binding<S::Interrupt, target::TargetInterruptHandler<S>>,
+    {
+        S::Interrupt::unpend();
+        unsafe { S::Interrupt::enable() };
+
+        target::I2cTargetFuture { i2c: self }
+    }
 }
 
 /// `async` version of [`I2c`].
@@ -64,6 +82,34 @@ where
 {
     pub(in super::super) i2c: I2c<C>,
     _dma_channel: D,
+    /// Masks and unpends `S::Interrupt` on drop. Kept as its own field,
+    /// rather than a `Drop` impl directly on [`I2cFuture`], so
+    /// [`free`](I2cFuture::free) and [`with_dma_channel`](I2cFuture::with_dma_channel)
+    /// can still move `i2c` out of `self`.
+    _interrupt_guard: InterruptGuard<C>,
+    /// Maximum time to wait for each bus event before aborting. `None`
+    /// waits forever, matching the pre-timeout behavior.
+    #[cfg(feature = "embassy-time")]
+    timeout: Option<embassy_time::Duration>,
+}
+
+/// Masks and unpends `S::Interrupt` when dropped, so a later `I2cFuture` on
+/// the same SERCOM doesn't get spuriously woken by a flag this instance
+/// left pending, or by a waker it never unregistered.
+struct InterruptGuard<C: AnyConfig> {
+    _sercom: PhantomData<C>,
+}
+
+impl<C, S> Drop for InterruptGuard<C>
+where
+    C: AnyConfig<Sercom = S>,
+    S: Sercom,
+{
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { S::Interrupt::disable() };
+        S::Interrupt::unpend();
+    }
 }
 
 #[cfg(feature = "dma")]
@@ -71,12 +117,170 @@ where
 /// mode. The type parameter `I` represents the DMA channel ID (`ChX`).
 pub type I2cFutureDma<C, I> = I2cFuture<C, crate::dmac::Channel<I, crate::dmac::ReadyFuture>>;
 
+/// Reason an async I2C transfer was aborted.
+///
+/// This lets a caller distinguish *why* [`Flags::ERROR`] became pending
+/// instead of only learning that it did. For example, a [`NoAcknowledge`]
+/// usually means the addressed device is absent or not ready, while
+/// [`ArbitrationLost`] means the transfer can simply be retried once the bus
+/// is free again.
+///
+/// [`NoAcknowledge`]: AbortReason::NoAcknowledge
+/// [`ArbitrationLost`]: AbortReason::ArbitrationLost
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbortReason {
+    /// The addressed device did not acknowledge its address or a data byte
+    /// (SERCOM `STATUS.RXNACK`).
+    NoAcknowledge,
+    /// Another master won arbitration for the bus (SERCOM `STATUS.ARBLOST`).
+    ArbitrationLost,
+    /// A bus error or illegal data length was detected (SERCOM
+    /// `STATUS.BUSERR`/`LENERR`).
+    BusError,
+    /// A target (slave) mode receive buffer overflowed, or an address match
+    /// arrived while a previous one hadn't been serviced yet.
+    Overflow,
+    /// The error flag was pending for a reason not covered above.
+    Other,
+}
+
+/// A target (slave) device address, either 7-bit or 10-bit.
+///
+/// Accepted anywhere an [`I2cFuture`] method takes an address via
+/// `impl Into<Address>`, so existing `u8` callers keep working unchanged
+/// while 10-bit devices can pass a `u16` or build an `Address::TenBit`
+/// directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Address {
+    /// A standard 7-bit address (`0x08..=0x77` are usable; the rest are
+    /// reserved by the I2C specification).
+    SevenBit(u8),
+    /// An extended 10-bit address (`0x000..=0x3FF`).
+    TenBit(u16),
+}
+
+impl From<u8> for Address {
+    #[inline]
+    fn from(addr: u8) -> Self {
+        Address::SevenBit(addr)
+    }
+}
+
+impl From<u16> for Address {
+    #[inline]
+    fn from(addr: u16) -> Self {
+        Address::TenBit(addr)
+    }
+}
+
+impl Address {
+    /// Reject reserved or out-of-range addresses before they ever reach the
+    /// hardware. The 7-bit reserved low block (`0x00..=0x07`, including the
+    /// general call `0x00`) and the reserved high block (`0x78..=0x7F`) are
+    /// rejected as [`AddressReserved`]; anything outside
+    /// the addressing scheme's bit width is rejected as
+    /// [`AddressOutOfRange`].
+    ///
+    /// [`AddressReserved`]: i2c::Error::AddressReserved
+    /// [`AddressOutOfRange`]: i2c::Error::AddressOutOfRange
+    fn validate(self) -> Result<Self, i2c::Error> {
+        match self {
+            Address::SevenBit(addr) => {
+                if addr > 0x7F {
+                    Err(i2c::Error::AddressOutOfRange(addr as u16))
+                } else if addr <= 0x07 || (0x78..=0x7F).contains(&addr) {
+                    Err(i2c::Error::AddressReserved(addr as u16))
+                } else {
+                    Ok(self)
+                }
+            }
+            Address::TenBit(addr) => {
+                if addr > 0x3FF {
+                    Err(i2c::Error::AddressOutOfRange(addr))
+                } else {
+                    Ok(self)
+                }
+            }
+        }
+    }
+}
+
 impl<C, S, D> I2cFuture<C, D>
 where
     C: AnyConfig<Sercom = S>,
     S: Sercom,
 {
-    async fn wait_flags(&mut self, flags_to_wait: Flags) {
+    /// Inspect the SERCOM `STATUS` register and translate any pending error
+    /// condition into an [`AbortReason`]. Returns `Ok(())` if none of the
+    /// error bits are set.
+    ///
+    /// `STATUS.RXNACK` must be checked unconditionally rather than gated on
+    /// `Flags::ERROR`: on this hardware a device NACK sets `STATUS.RXNACK`
+    /// and `INTFLAG.MB`, but does *not* set `INTFLAG.ERROR` (`ERROR` is only
+    /// ARBLOST/BUSERR/LENERR/timeout). Only the "none of the known bits
+    /// explain it" fallback is conditioned on `Flags::ERROR` actually being
+    /// pending.
+    fn check_error(&self) -> Result<(), i2c::Error> {
+        let status = self
+            .i2c
+            .config
+            .as_ref()
+            .registers
+            .i2c_master()
+            .status
+            .read();
+
+        if status.rxnack().bit_is_set() {
+            Err(i2c::Error::Abort(AbortReason::NoAcknowledge))
+        } else if status.arblost().bit_is_set() {
+            Err(i2c::Error::Abort(AbortReason::ArbitrationLost))
+        } else if status.buserr().bit_is_set() || status.lenerr().bit_is_set() {
+            Err(i2c::Error::Abort(AbortReason::BusError))
+        } else if self
+            .i2c
+            .config
+            .as_ref()
+            .registers
+            .read_flags()
+            .contains(Flags::ERROR)
+        {
+            // `Flags::ERROR` is pending but none of the known status bits
+            // explain why. Still fail the transfer rather than silently
+            // treating an unexplained error wakeup as success.
+            Err(i2c::Error::Abort(AbortReason::Other))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Write the SERCOM I2C master `ADDR` register to (re)start a transfer
+    /// to `addr`. On this hardware, writing `ADDR` is what actually issues
+    /// the START (or repeated START) condition; there is no separate "go"
+    /// command. Shared by the non-DMA and DMA paths alike, since both need
+    /// to address the bus the same way.
+    ///
+    /// For [`Address::TenBit`], `ADDR.TENBITEN` tells the SERCOM to frame
+    /// the extra address byte itself: the initial write-direction START
+    /// clocks out both address bytes, while the repeated START that
+    /// switches to a read clocks out only the short first byte, per the
+    /// 10-bit addressing sequence in the datasheet. Software just keeps
+    /// writing the full 10-bit address with `TENBITEN` set each time.
+    fn start(&mut self, addr: Address, direction_read: bool) {
+        let i2cm = self.i2c.config.as_mut().registers.i2c_master();
+        match addr {
+            Address::SevenBit(addr) => i2cm.addr.write(|w| unsafe {
+                w.tenbiten().clear_bit();
+                w.addr()
+                    .bits((u16::from(addr) << 1) | u16::from(direction_read))
+            }),
+            Address::TenBit(addr) => i2cm.addr.write(|w| unsafe {
+                w.tenbiten().set_bit();
+                w.addr().bits((addr << 1) | u16::from(direction_read))
+            }),
+        }
+    }
+
+    async fn wait_flags_unbounded(&mut self, flags_to_wait: Flags) {
         core::future::poll_fn(|cx| {
             // Scope maybe_pending so we don't forget to re-poll the register later down.
             {
@@ -100,6 +304,53 @@ where
         })
         .await;
     }
+
+    /// Wait for `flags_to_wait`, aborting with [`i2c::Error::Timeout`] if
+    /// [`with_timeout`](I2cFuture::with_timeout) was configured and the
+    /// wait outlasts it.
+    async fn wait_flags(&mut self, flags_to_wait: Flags) -> Result<(), i2c::Error> {
+        #[cfg(feature = "embassy-time")]
+        if let Some(timeout) = self.timeout {
+            use embassy_futures::select::{select, Either};
+
+            return match select(
+                self.wait_flags_unbounded(flags_to_wait),
+                embassy_time::Timer::after(timeout),
+            )
+            .await
+            {
+                Either::First(()) => Ok(()),
+                Either::Second(()) => {
+                    self.abort_on_timeout();
+                    Err(i2c::Error::Timeout)
+                }
+            };
+        }
+
+        self.wait_flags_unbounded(flags_to_wait).await;
+        Ok(())
+    }
+
+    /// Bound every future wait for a bus flag to at most `timeout`. If the
+    /// bus stalls (e.g. SDA held low by a wedged device) past `timeout`, the
+    /// pending command is aborted and [`i2c::Error::Timeout`] is returned
+    /// instead of hanging forever. DMA transfers wait on the DMA channel
+    /// rather than on bus flags and are not bounded by `timeout`.
+    #[cfg(feature = "embassy-time")]
+    pub fn with_timeout(mut self, timeout: embassy_time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Abort the in-flight command after a timed-out wait: issue STOP so
+    /// the bus is released, clear any pending flags, and disable
+    /// interrupts so a later transfer doesn't see a stale wakeup.
+    #[cfg(feature = "embassy-time")]
+    fn abort_on_timeout(&mut self) {
+        self.i2c.cmd_stop();
+        self.i2c.config.as_mut().registers.clear_flags(Flags::all());
+        self.i2c.disable_interrupts(Flags::all());
+    }
 }
 
 impl<C, S> I2cFuture<C, NoneT>
@@ -116,6 +367,9 @@ where
         I2cFuture {
             i2c: self.i2c,
             _dma_channel: dma_channel,
+            _interrupt_guard: self._interrupt_guard,
+            #[cfg(feature = "embassy-time")]
+            timeout: self.timeout,
         }
     }
 
@@ -124,45 +378,58 @@ where
         self.i2c
     }
 
-    /// Asynchronously write from a buffer.
-    #[inline]
-    pub async fn write(&mut self, addr: u8, bytes: &[u8]) -> Result<(), i2c::Error> {
-        self.i2c.config.as_mut().registers.start_write(addr)?;
-
+    /// Write `bytes` to the bus, assuming addressing has already happened.
+    /// Leaves the bus owned, without an implicit trailing STOP, so a caller
+    /// can either follow up with a repeated START or send the STOP itself.
+    async fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), i2c::Error> {
         for b in bytes {
-            self.wait_flags(Flags::MB | Flags::ERROR).await;
-            self.i2c.read_status().check_bus_error()?;
+            self.wait_flags(Flags::MB | Flags::ERROR).await?;
+            if let Err(e) = self.check_error() {
+                // Release the bus so a NACK'd device isn't left holding it.
+                self.i2c.cmd_stop();
+                return Err(e);
+            }
 
             self.i2c.config.as_mut().registers.write_one(*b);
         }
 
-        self.i2c.cmd_stop();
-
         Ok(())
     }
 
-    /// Asynchronously read into a buffer.
-    #[inline]
-    pub async fn read(&mut self, addr: u8, buffer: &mut [u8]) -> Result<(), i2c::Error> {
-        self.i2c.config.as_mut().registers.start_read(addr)?;
-
-        // Some manual iterator gumph because we need to ack bytes after the first.
+    /// Fill `buffer` from the bus, assuming addressing has already happened.
+    /// `first_byte_pending` must only be `true` right after a read-direction
+    /// [`start`](I2cFuture::start), where the first byte arrives without a
+    /// command and [`Flags::SB`] becomes pending for it; otherwise
+    /// the first byte needs an explicit ACK via `cmd_read` like every other
+    /// byte. Does not arm the final NACK or send a STOP, so multiple reads
+    /// can be coalesced into one bus phase.
+    async fn read_bytes(
+        &mut self,
+        buffer: &mut [u8],
+        first_byte_pending: bool,
+    ) -> Result<(), i2c::Error> {
         let mut iter = buffer.iter_mut();
-        *iter.next().expect("buffer len is at least 1") = self.read_one().await;
-
-        loop {
-            match iter.next() {
-                None => break,
-                Some(dest) => {
-                    // Ack the last byte so we can receive another one
-                    self.i2c.config.as_mut().registers.cmd_read();
-                    *dest = self.read_one().await;
-                }
-            }
+        let Some(first) = iter.next() else {
+            return Ok(());
+        };
+
+        if !first_byte_pending {
+            self.i2c.config.as_mut().registers.cmd_read();
         }
+        *first = self.read_one().await?;
 
-        // Arrange to send NACK on next command to
-        // stop slave from transmitting more data
+        for dest in iter {
+            // Ack the previous byte so we can receive another one
+            self.i2c.config.as_mut().registers.cmd_read();
+            *dest = self.read_one().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Arm a NACK for the next received byte, so the slave stops
+    /// transmitting once the caller's STOP takes effect.
+    fn arm_nack(&mut self) {
         self.i2c
             .config
             .as_mut()
@@ -170,42 +437,73 @@ where
             .i2c_master()
             .ctrlb
             .modify(|_, w| w.ackact().set_bit());
+    }
+
+    /// Asynchronously write from a buffer.
+    #[inline]
+    pub async fn write(
+        &mut self,
+        addr: impl Into<Address>,
+        bytes: &[u8],
+    ) -> Result<(), i2c::Error> {
+        let addr = addr.into().validate()?;
+        self.start(addr, false);
+        self.write_bytes(bytes).await?;
+        self.i2c.cmd_stop();
+        Ok(())
+    }
 
+    /// Asynchronously read into a buffer.
+    #[inline]
+    pub async fn read(
+        &mut self,
+        addr: impl Into<Address>,
+        buffer: &mut [u8],
+    ) -> Result<(), i2c::Error> {
+        let addr = addr.into().validate()?;
+        self.start(addr, true);
+        self.read_bytes(buffer, true).await?;
+        self.arm_nack();
+        self.i2c.cmd_stop();
         Ok(())
     }
 
     /// Asynchronously write from a buffer, then read into a buffer. This is an
     /// extremely common pattern: writing a register address, then
-    /// read its value from the slave.
+    /// read its value from the slave. Uses a repeated START between the
+    /// write and read phases instead of a STOP, since many devices abort or
+    /// reset their internal register pointer on a STOP.
     #[inline]
     pub async fn write_read(
         &mut self,
-        addr: u8,
+        addr: impl Into<Address>,
         write_buf: &[u8],
         read_buf: &mut [u8],
     ) -> Result<(), i2c::Error> {
-        self.write(addr, write_buf).await?;
-        self.read(addr, read_buf).await?;
+        let addr = addr.into().validate()?;
+        self.start(addr, false);
+        self.write_bytes(write_buf).await?;
+
+        self.start(addr, true);
+        self.read_bytes(read_buf, true).await?;
+
+        self.arm_nack();
+        self.i2c.cmd_stop();
         Ok(())
     }
 
-    async fn read_one(&mut self) -> u8 {
-        self.wait_flags(Flags::SB | Flags::ERROR).await;
-        self.i2c.config.as_mut().registers.read_one()
+    async fn read_one(&mut self) -> Result<u8, i2c::Error> {
+        self.wait_flags(Flags::SB | Flags::ERROR).await?;
+        if let Err(e) = self.check_error() {
+            // As in `write_bytes`, release the bus so a NACK'd or errored
+            // device isn't left holding it.
+            self.i2c.cmd_stop();
+            return Err(e);
+        }
+        Ok(self.i2c.config.as_mut().registers.read_one())
     }
 }
 
-// impl<C, N, D> Drop for I2cFuture<C, N, D>
-// where
-//     C: AnyConfig,
-//     N: InterruptNumber,
-// {
-//     #[inline]
-//     fn drop(&mut self) {
-//         cortex_m::peripheral::NVIC::mask(self.irq_number);
-//     }
-// }
-
 impl<C, N> AsRef<I2c<C>> for I2cFuture<C, N>
 where
     C: AnyConfig,
@@ -230,7 +528,7 @@ where
 
 mod impl_ehal {
     use super::*;
-    use embedded_hal_async::i2c::{ErrorType, I2c as I2cTrait, Operation};
+    use embedded_hal_async::i2c::{ErrorType, I2c as I2cTrait, Operation, TenBitAddress};
 
     impl<C, D> ErrorType for I2cFuture<C, D>
     where
@@ -272,15 +570,100 @@ mod impl_ehal {
             address: u8,
             operations: &'a mut [embedded_hal_async::i2c::Operation<'b>],
         ) -> Result<(), Self::Error> {
-            for op in operations {
-                match op {
-                    Operation::Read(buf) => self.read(address, buf).await?,
-                    Operation::Write(buf) => self.write(address, buf).await?,
-                }
-            }
+            run_transaction(self, address, operations).await
+        }
+    }
 
+    /// Same as the `SevenBitAddress` impl above, but for 10-bit addressed
+    /// targets.
+    impl<C> I2cTrait<TenBitAddress> for I2cFuture<C>
+    where
+        C: AnyConfig,
+    {
+        #[inline]
+        async fn read(&mut self, address: u16, buffer: &mut [u8]) -> Result<(), Self::Error> {
+            self.read(address, buffer).await?;
+            Ok(())
+        }
+
+        #[inline]
+        async fn write(&mut self, address: u16, bytes: &[u8]) -> Result<(), Self::Error> {
+            self.write(address, bytes).await?;
             Ok(())
         }
+
+        #[inline]
+        async fn write_read(
+            &mut self,
+            address: u16,
+            wr_buffer: &[u8],
+            rd_buffer: &mut [u8],
+        ) -> Result<(), Self::Error> {
+            self.write_read(address, wr_buffer, rd_buffer).await?;
+            Ok(())
+        }
+
+        #[inline]
+        async fn transaction<'mut_self, 'a, 'b>(
+            &'mut_self mut self,
+            address: u16,
+            operations: &'a mut [embedded_hal_async::i2c::Operation<'b>],
+        ) -> Result<(), Self::Error> {
+            run_transaction(self, address, operations).await
+        }
+    }
+
+    /// Drive `operations` against the bus, coalescing consecutive operations
+    /// that share a direction into a single bus phase, issuing a repeated
+    /// START between operations of differing direction, and a single STOP
+    /// after the last operation. This is what [`embedded_hal_async::i2c::I2c::transaction`]
+    /// expects.
+    async fn run_transaction<C, S>(
+        fut: &mut I2cFuture<C, NoneT>,
+        address: impl Into<Address>,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), i2c::Error>
+    where
+        C: AnyConfig<Sercom = S>,
+        S: Sercom,
+    {
+        let address = address.into().validate()?;
+
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum Direction {
+            Read,
+            Write,
+        }
+
+        let mut direction = None;
+
+        for op in operations {
+            let op_direction = match op {
+                Operation::Read(_) => Direction::Read,
+                Operation::Write(_) => Direction::Write,
+            };
+
+            let continuing = direction == Some(op_direction);
+            if !continuing {
+                fut.start(address, op_direction == Direction::Read);
+            }
+
+            match op {
+                Operation::Write(buf) => fut.write_bytes(buf).await?,
+                Operation::Read(buf) => fut.read_bytes(buf, !continuing).await?,
+            }
+
+            direction = Some(op_direction);
+        }
+
+        if let Some(Direction::Read) = direction {
+            fut.arm_nack();
+        }
+        if direction.is_some() {
+            fut.i2c.cmd_stop();
+        }
+
+        Ok(())
     }
 
     #[cfg(feature = "dma")]
@@ -318,15 +701,49 @@ mod impl_ehal {
             address: u8,
             operations: &'a mut [embedded_hal_async::i2c::Operation<'b>],
         ) -> Result<(), Self::Error> {
-            for op in operations {
-                match op {
-                    Operation::Read(buf) => self.read(address, buf).await?,
-                    Operation::Write(buf) => self.write(address, buf).await?,
-                }
-            }
+            super::dma::run_transaction(self, address, operations).await
+        }
+    }
+
+    /// Same as the `SevenBitAddress` DMA impl above, but for 10-bit
+    /// addressed targets.
+    #[cfg(feature = "dma")]
+    impl<C, D> I2cTrait<TenBitAddress> for I2cFuture<C, D>
+    where
+        C: AnyConfig,
+        D: crate::dmac::AnyChannel<Status = crate::dmac::ReadyFuture>,
+    {
+        #[inline]
+        async fn read(&mut self, address: u16, buffer: &mut [u8]) -> Result<(), Self::Error> {
+            self.read(address, buffer).await?;
+            Ok(())
+        }
 
+        #[inline]
+        async fn write(&mut self, address: u16, bytes: &[u8]) -> Result<(), Self::Error> {
+            self.write(address, bytes).await?;
             Ok(())
         }
+
+        #[inline]
+        async fn write_read(
+            &mut self,
+            address: u16,
+            wr_buffer: &[u8],
+            rd_buffer: &mut [u8],
+        ) -> Result<(), Self::Error> {
+            self.write_read(address, wr_buffer, rd_buffer).await?;
+            Ok(())
+        }
+
+        #[inline]
+        async fn transaction<'mut_self, 'a, 'b>(
+            &'mut_self mut self,
+            address: u16,
+            operations: &'a mut [embedded_hal_async::i2c::Operation<'b>],
+        ) -> Result<(), Self::Error> {
+            super::dma::run_transaction(self, address, operations).await
+        }
     }
 }
 
@@ -335,6 +752,11 @@ mod dma {
     use super::*;
     use crate::dmac::{AnyChannel, ReadyFuture};
     use crate::sercom::async_dma::{read_dma, write_dma, SercomPtr};
+    use embedded_hal_async::i2c::Operation;
+
+    /// The SERCOM `LENGTH` register is only 8 bits wide, so a single DMA
+    /// burst can move at most this many bytes.
+    const MAX_CHUNK_LEN: usize = 255;
 
     impl<C, S, D> I2cFuture<C, D>
     where
@@ -346,60 +768,402 @@ mod dma {
             SercomPtr(self.i2c.data_ptr())
         }
 
-        /// Asynchronously write from a buffer using DMA.
-        #[inline]
-        pub async fn write(&mut self, address: u8, bytes: &[u8]) -> Result<(), i2c::Error> {
-            self.i2c.init_dma_transfer()?;
+        /// Reload `LENGTH` for the next DMA burst without an intervening
+        /// STOP or re-addressing, so a multi-chunk transfer's chunks stay
+        /// one bus transaction.
+        ///
+        /// `auto_complete` arms the hardware's own STOP-on-complete (write)
+        /// or NACK-then-STOP-on-complete (read) behavior for this chunk. It
+        /// must only be set for the chunk that is unambiguously the last
+        /// chunk of the last phase of the whole transfer; setting it any
+        /// earlier would let the hardware release the bus before the next
+        /// chunk gets reloaded.
+        fn reload_length(&mut self, len: u8, auto_complete: bool) {
+            self.i2c
+                .config
+                .as_mut()
+                .registers
+                .i2c_master()
+                .length
+                .write(|w| unsafe { w.len().bits(len).lenen().bit(auto_complete) });
+        }
 
-            // SAFETY: Using SercomPtr and ImmutableSlice is safe because we hold on
-            // to &mut self and bytes as long as the transfer hasn't completed.
-            let i2c_ptr = self.sercom_ptr();
+        /// Drive one write phase of a DMA transfer, chunked to
+        /// [`MAX_CHUNK_LEN`]. The caller addresses the target beforehand
+        /// (via [`start`](I2cFuture::start)); only the final chunk is
+        /// allowed to auto-complete the bus transaction, and only when
+        /// `is_final_phase` says this is the last phase of the whole
+        /// transfer.
+        async fn dma_write_phase(
+            &mut self,
+            bytes: &[u8],
+            is_final_phase: bool,
+        ) -> Result<(), i2c::Error> {
+            assert!(!bytes.is_empty());
 
-            let len = bytes.len();
-            assert!(len > 0 && len <= 255);
-            self.i2c.start_dma_write(address, len as u8);
+            let mut chunks = bytes.chunks(MAX_CHUNK_LEN).peekable();
+            while let Some(chunk) = chunks.next() {
+                let is_last_chunk = chunks.peek().is_none();
+                self.reload_length(chunk.len() as u8, is_final_phase && is_last_chunk);
 
-            write_dma::<_, S>(&mut self._dma_channel, i2c_ptr, bytes)
-                .await
-                .map_err(i2c::Error::Dma)?;
+                // SAFETY: Using SercomPtr and ImmutableSlice is safe because we hold on
+                // to &mut self and bytes as long as the transfer hasn't completed.
+                let i2c_ptr = self.sercom_ptr();
+                write_dma::<_, S>(&mut self._dma_channel, i2c_ptr, chunk)
+                    .await
+                    .map_err(i2c::Error::Dma)?;
+            }
 
             Ok(())
         }
 
-        /// Asynchronously read into a buffer using DMA.
-        #[inline]
-        pub async fn read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), i2c::Error> {
-            self.i2c.init_dma_transfer()?;
+        /// Drive one read phase of a DMA transfer, chunked to
+        /// [`MAX_CHUNK_LEN`]. Only the final byte of the final chunk of the
+        /// final phase is NACK'd; every other chunk or phase boundary keeps
+        /// ACKing so the target keeps streaming across the reload. The
+        /// caller addresses the target beforehand (via
+        /// [`start`](I2cFuture::start)).
+        async fn dma_read_phase(
+            &mut self,
+            buffer: &mut [u8],
+            is_final_phase: bool,
+        ) -> Result<(), i2c::Error> {
+            assert!(!buffer.is_empty());
 
-            // SAFETY: Using SercomPtr is safe because we hold on
-            // to &mut self as long as the transfer hasn't completed.
-            let i2c_ptr = self.sercom_ptr();
+            let mut chunks = buffer.chunks_mut(MAX_CHUNK_LEN).peekable();
+            while let Some(chunk) = chunks.next() {
+                let is_last_chunk = chunks.peek().is_none();
+                let auto_complete = is_final_phase && is_last_chunk;
 
-            let len = buffer.len();
-            assert!(len > 0 && len <= 255);
-            self.i2c.start_dma_read(address, len as u8);
+                self.i2c
+                    .config
+                    .as_mut()
+                    .registers
+                    .i2c_master()
+                    .ctrlb
+                    .modify(|_, w| w.ackact().bit(auto_complete));
+                self.reload_length(chunk.len() as u8, auto_complete);
 
-            read_dma::<_, S>(&mut self._dma_channel, i2c_ptr, buffer)
-                .await
-                .map_err(i2c::Error::Dma)?;
+                // SAFETY: Using SercomPtr is safe because we hold on
+                // to &mut self as long as the transfer hasn't completed.
+                let i2c_ptr = self.sercom_ptr();
+                read_dma::<_, S>(&mut self._dma_channel, i2c_ptr, chunk)
+                    .await
+                    .map_err(i2c::Error::Dma)?;
+            }
 
             Ok(())
         }
 
+        /// Asynchronously write from a buffer using DMA. Buffers longer than
+        /// [`MAX_CHUNK_LEN`] are transparently split into back-to-back
+        /// chunks, each its own `LENGTH`-bounded DMA burst, so a single
+        /// logical write of arbitrary length stays one bus transaction.
+        ///
+        /// [`start`](I2cFuture::start) addresses the target once, same
+        /// as the non-DMA path, so 7-bit and 10-bit addresses are both
+        /// handled correctly.
+        #[inline]
+        pub async fn write(
+            &mut self,
+            address: impl Into<Address>,
+            bytes: &[u8],
+        ) -> Result<(), i2c::Error> {
+            let address = address.into().validate()?;
+            self.i2c.init_dma_transfer()?;
+            self.start(address, false);
+            self.dma_write_phase(bytes, true).await
+        }
+
+        /// Asynchronously read into a buffer using DMA. Buffers longer than
+        /// [`MAX_CHUNK_LEN`] are transparently split into back-to-back
+        /// chunks, each its own `LENGTH`-bounded DMA burst.
+        ///
+        /// [`start`](I2cFuture::start) addresses the target once, same
+        /// as the non-DMA path, so 7-bit and 10-bit addresses are both
+        /// handled correctly.
+        #[inline]
+        pub async fn read(
+            &mut self,
+            address: impl Into<Address>,
+            buffer: &mut [u8],
+        ) -> Result<(), i2c::Error> {
+            let address = address.into().validate()?;
+            self.i2c.init_dma_transfer()?;
+            self.start(address, true);
+            self.dma_read_phase(buffer, true).await
+        }
+
         /// Asynchronously write from a buffer, then read into a buffer, all
         /// using DMA. This is an extremely common pattern: writing a
-        /// register address, then read its value from the slave.
+        /// register address, then reading its value from the slave.
+        ///
+        /// The write phase never auto-completes (`is_final_phase: false`):
+        /// instead of letting `LENGTH` STOP the bus, a fresh `start`
+        /// issues a repeated START into the read phase, which is the one
+        /// allowed to STOP. This keeps the whole write-then-read as a
+        /// single bus transaction instead of two independent auto-STOP
+        /// transfers.
         #[inline]
         pub async fn write_read(
             &mut self,
-            addr: u8,
+            addr: impl Into<Address>,
             write_buf: &[u8],
             read_buf: &mut [u8],
         ) -> Result<(), i2c::Error> {
-            self.write(addr, write_buf).await?;
-            // TODO may need some sort of delay here??
-            self.read(addr, read_buf).await?;
+            let addr = addr.into().validate()?;
+            self.i2c.init_dma_transfer()?;
+
+            self.start(addr, false);
+            self.dma_write_phase(write_buf, false).await?;
+
+            // Repeated START into the read phase.
+            self.start(addr, true);
+            self.dma_read_phase(read_buf, true).await
+        }
+    }
+
+    /// DMA counterpart to [`impl_ehal::run_transaction`](super::impl_ehal).
+    /// Runs each operation as its own chunked DMA phase via
+    /// [`dma_write_phase`](I2cFuture::dma_write_phase)/
+    /// [`dma_read_phase`](I2cFuture::dma_read_phase), but only (re)addresses
+    /// the target when the direction changes, so consecutive operations
+    /// that share a direction form one bus phase and a multi-operation
+    /// [`embedded_hal_async::i2c::I2c::transaction`] stays one bus
+    /// transaction just like the non-DMA path. Only the final chunk of the
+    /// final operation is allowed to auto-complete and release the bus.
+    pub(super) async fn run_transaction<C, S, D>(
+        fut: &mut I2cFuture<C, D>,
+        address: impl Into<Address>,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), i2c::Error>
+    where
+        C: AnyConfig<Sercom = S>,
+        S: Sercom,
+        D: AnyChannel<Status = ReadyFuture>,
+    {
+        let address = address.into().validate()?;
+        fut.i2c.init_dma_transfer()?;
+
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum Direction {
+            Read,
+            Write,
+        }
+
+        fn direction_of(op: &Operation<'_>) -> Direction {
+            match op {
+                Operation::Read(_) => Direction::Read,
+                Operation::Write(_) => Direction::Write,
+            }
+        }
+
+        let Some(last_index) = operations.len().checked_sub(1) else {
+            return Ok(());
+        };
+
+        let mut direction = None;
+
+        for (i, op) in operations.iter_mut().enumerate() {
+            let op_direction = direction_of(op);
+            if direction != Some(op_direction) {
+                fut.start(address, op_direction == Direction::Read);
+            }
+            direction = Some(op_direction);
+
+            let is_final_phase = i == last_index;
+
+            match op {
+                Operation::Write(bytes) => fut.dma_write_phase(bytes, is_final_phase).await?,
+                Operation::Read(buffer) => fut.dma_read_phase(buffer, is_final_phase).await?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Async I2C target (slave) mode, built on the SERCOM `i2cs` register block.
+///
+/// Unlike [`I2cFuture`], which drives the bus as a controller, an
+/// [`I2cTargetFuture`] waits to be addressed by someone else's controller
+/// and responds to the read or write it requests. Create one by calling
+/// [`I2c::into_target_future`].
+mod target {
+    use super::*;
+
+    /// Interrupt handler for async I2C target (slave) mode operations.
+    pub struct TargetInterruptHandler<S: Sercom> {
+        _private: (),
+        _sercom: PhantomData<S>,
+    }
+
+    impl<S: Sercom> Handler<S::Interrupt> for TargetInterruptHandler<S> {
+        #[inline]
+        unsafe fn on_interrupt() {
+            let mut peripherals = unsafe { crate::pac::Peripherals::steal() };
+            let i2cs = S::reg_block(&mut peripherals).i2cs();
+            let flags_to_check = Flags::all();
+            let flags_pending = Flags::from_bits_truncate(i2cs.intflag.read().bits());
+
+            // Disable interrupts, but don't clear the flags. The future will take care of
+            // clearing flags and re-enabling interrupts when woken.
+            if flags_to_check.contains(flags_pending) {
+                i2cs.intenclr
+                    .write(|w| unsafe { w.bits(flags_pending.bits()) });
+                S::rx_waker().wake();
+            }
+        }
+    }
+
+    /// The transfer direction requested by the controller that addressed us.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Direction {
+        /// The controller wants to read from us; answer with
+        /// [`respond_to_read`](I2cTargetFuture::respond_to_read).
+        Read,
+        /// The controller wants to write to us; answer with
+        /// [`respond_to_write`](I2cTargetFuture::respond_to_write).
+        Write,
+    }
+
+    /// `async` target (slave) mode counterpart to [`I2cFuture`].
+    ///
+    /// Create this struct by calling
+    /// [`I2c::into_target_future`](I2c::into_target_future).
+    pub struct I2cTargetFuture<C>
+    where
+        C: AnyConfig,
+    {
+        pub(super) i2c: I2c<C>,
+    }
+
+    impl<C, S> I2cTargetFuture<C>
+    where
+        C: AnyConfig<Sercom = S>,
+        S: Sercom,
+    {
+        async fn wait_flags(&mut self, flags_to_wait: Flags) {
+            core::future::poll_fn(|cx| {
+                {
+                    let maybe_pending = self.i2c.config.as_ref().registers.read_flags();
+                    if flags_to_wait.intersects(maybe_pending) {
+                        return Poll::Ready(());
+                    }
+                }
+
+                self.i2c.disable_interrupts(Flags::all());
+                // By convention, I2C uses the sercom's RX waker.
+                S::rx_waker().register(cx.waker());
+                self.i2c.enable_interrupts(flags_to_wait);
+                let maybe_pending = self.i2c.config.as_ref().registers.read_flags();
+
+                if !flags_to_wait.intersects(maybe_pending) {
+                    Poll::Pending
+                } else {
+                    Poll::Ready(())
+                }
+            })
+            .await;
+        }
+
+        /// Inspect the SERCOM target-mode `STATUS` register and translate
+        /// any pending error condition into an [`AbortReason`].
+        ///
+        /// `STATUS.RXNACK` is deliberately not treated as an error here: in
+        /// target-transmit mode it is how the controller signals it has
+        /// read all the bytes it wants, and is set on every successful
+        /// [`respond_to_read`](I2cTargetFuture::respond_to_read).
+        fn check_error(&self) -> Result<(), i2c::Error> {
+            let status = self.i2c.config.as_ref().registers.i2c_slave().status.read();
+
+            if status.coll().bit_is_set() {
+                Err(i2c::Error::Abort(AbortReason::ArbitrationLost))
+            } else if status.buserr().bit_is_set() {
+                Err(i2c::Error::Abort(AbortReason::BusError))
+            } else {
+                Ok(())
+            }
+        }
+
+        /// Return the underlying [`I2c`].
+        pub fn free(self) -> I2c<C> {
+            self.i2c
+        }
+
+        /// Wait to be addressed by a controller, returning the address it
+        /// matched against and the direction it wants to transfer in. The
+        /// bus is clock-stretched (held) by hardware for as long as this
+        /// future goes unpolled, so a controller simply waits rather than
+        /// seeing a NACK.
+        pub async fn listen(&mut self) -> Result<(u8, Direction), i2c::Error> {
+            self.wait_flags(Flags::AMATCH | Flags::ERROR).await;
+            self.check_error()?;
+
+            let i2cs = self.i2c.config.as_ref().registers.i2c_slave();
+            let status = i2cs.status.read();
+            let address = i2cs.addr.read().addr().bits() as u8;
+            let direction = if status.dir().bit_is_set() {
+                Direction::Read
+            } else {
+                Direction::Write
+            };
+
+            Ok((address, direction))
+        }
+
+        /// Answer a pending [`Direction::Read`] by clocking `bytes` out to
+        /// the controller.
+        pub async fn respond_to_read(&mut self, bytes: &[u8]) -> Result<(), i2c::Error> {
+            // Ack the address match so the controller can start clocking.
+            self.i2c.config.as_mut().registers.cmd_ack();
+
+            for b in bytes {
+                self.wait_flags(Flags::DRDY | Flags::ERROR).await;
+                self.check_error()?;
+                self.i2c.config.as_mut().registers.write_one(*b);
+            }
+
+            // The controller NACKs the final byte and issues STOP (or a
+            // repeated START) once it has what it needs.
+            self.wait_flags(Flags::PREC | Flags::ERROR).await;
+            self.check_error()?;
+            self.i2c.config.as_mut().registers.clear_flags(Flags::PREC);
+
+            Ok(())
+        }
+
+        /// Answer a pending [`Direction::Write`] by receiving into
+        /// `buffer`. Returns early, leaving the rest of `buffer` untouched, if
+        /// the controller sends STOP before `buffer` is full; the number of
+        /// bytes received is not reported.
+        pub async fn respond_to_write(&mut self, buffer: &mut [u8]) -> Result<(), i2c::Error> {
+            self.i2c.config.as_mut().registers.cmd_ack();
+
+            for dest in buffer.iter_mut() {
+                self.wait_flags(Flags::DRDY | Flags::PREC | Flags::ERROR)
+                    .await;
+                self.check_error()?;
+
+                if self
+                    .i2c
+                    .config
+                    .as_ref()
+                    .registers
+                    .read_flags()
+                    .contains(Flags::PREC)
+                {
+                    self.i2c.config.as_mut().registers.clear_flags(Flags::PREC);
+                    return Ok(());
+                }
+
+                *dest = self.i2c.config.as_mut().registers.read_one();
+                self.i2c.config.as_mut().registers.cmd_ack();
+            }
+
             Ok(())
         }
     }
-}
\ No newline at end of file
+}
+
+pub use target::{Direction, I2cTargetFuture, TargetInterruptHandler};